@@ -45,6 +45,19 @@
 //!   let downsampled = lttb(raw, 3);
 //! }
 //! ```
+//!
+//! ## Other entry points
+//!
+//! - [`lttb_into`] is a variant of [`lttb`] that borrows its input and writes
+//!   into a caller-provided, reusable `Vec<DataPoint>`.
+//! - [`lttb_sep`] and [`lttb_indices`] accept separate `x` and `y` slices of
+//!   any numeric type (via the [`LttbParam`] trait) instead of a
+//!   `Vec<DataPoint>` of `f64`s, so columnar buffers can be downsampled
+//!   without being zipped together first. `lttb_indices` returns the chosen
+//!   *indices*, which is useful for downsampling several series that share
+//!   one `x` axis consistently.
+//! - [`min_max_lttb`] is an accelerated variant for very large inputs that
+//!   preselects local extrema with a MinMax pass before running LTTB.
 
 /// DataPoint
 ///
@@ -67,22 +80,131 @@ impl DataPoint {
     }
 }
 
+/// Downsamples `data` to `threshold` points using the largest triangle three
+/// buckets algorithm, writing the chosen points into `out` instead of
+/// allocating a new `Vec`.
+///
+/// `out` is cleared before being filled, but its existing capacity is kept,
+/// so reusing the same `out` across repeated calls (e.g. redrawing a chart
+/// on every frame) avoids churning the allocator.
+///
+/// When `data` has more than `threshold` points, `out` ends up holding just
+/// the first point when `threshold == 1`, and just the first and last points
+/// when `threshold == 2`, since neither leaves room for an interior point.
+/// Otherwise (or when `threshold == 0`) every point of `data` is kept.
+///
+/// Built on the same [`select_indices`] core as [`lttb_indices`], so the
+/// bucket-selection algorithm lives in exactly one place; the index buffer
+/// `select_indices` allocates is the only allocation this function does
+/// beyond what `out`'s existing capacity can absorb.
+pub fn lttb_into(data: &[DataPoint], threshold: usize, out: &mut Vec<DataPoint>) {
+    out.clear();
+    out.extend(
+        select_indices(data.len(), threshold, |i| data[i].x, |i| data[i].y)
+            .into_iter()
+            .map(|i| data[i]),
+    );
+}
+
+/// Downsamples `data` to `threshold` points using the largest triangle three
+/// buckets algorithm, and returns the chosen points as a new `Vec<DataPoint>`.
+///
+/// This is a thin wrapper around [`lttb_into`] for callers that don't need to
+/// reuse an output buffer across calls.
 pub fn lttb(data: Vec<DataPoint>, threshold: usize) -> Vec<DataPoint> {
-    if threshold >= data.len() || threshold == 0 {
+    let mut out = Vec::new();
+    lttb_into(&data, threshold, &mut out);
+    out
+}
+
+/// A numeric type that can be used as the `x` or `y` coordinate of a point
+/// passed to [`lttb_sep`] or [`lttb_indices`].
+///
+/// It is implemented for every `Copy` type that implements
+/// `num_traits::ToPrimitive`,
+/// so callers can downsample columnar buffers (e.g. a `&[i64]` timestamp
+/// column alongside a `&[f32]` value column) without first copying them into
+/// [`DataPoint`]s.
+pub trait LttbParam: Copy {
+    /// Converts this value to an `f64` for use in the downsampling math.
+    fn to_f64(self) -> f64;
+}
+
+impl<T> LttbParam for T
+where
+    T: num_traits::ToPrimitive + Copy,
+{
+    fn to_f64(self) -> f64 {
+        num_traits::ToPrimitive::to_f64(&self).expect("value cannot be represented as an f64")
+    }
+}
+
+/// Downsamples a structure-of-arrays time series `(xs, ys)` to `threshold`
+/// points using the largest triangle three buckets algorithm, and returns the
+/// *indices* of the chosen points rather than materializing new points.
+///
+/// This is useful when several value series share one `x` axis (e.g.
+/// open/high/low/close, or readings from multiple sensors): run
+/// `lttb_indices` once on a reference series, then gather the same indices
+/// from every other column so all series stay aligned, instead of each one
+/// picking its own independent set of points.
+///
+/// When `xs` has more than `threshold` points, returns `vec![0]` when
+/// `threshold == 1` and `vec![0, xs.len() - 1]` when `threshold == 2`, since
+/// neither leaves room for an interior point. Otherwise (or when
+/// `threshold == 0`) every index of `xs` is returned.
+///
+/// # Panics
+///
+/// Panics if `xs` and `ys` do not have the same length.
+pub fn lttb_indices<X, Y>(xs: &[X], ys: &[Y], threshold: usize) -> Vec<usize>
+where
+    X: LttbParam,
+    Y: LttbParam,
+{
+    assert_eq!(xs.len(), ys.len(), "xs and ys must have the same length");
+
+    select_indices(xs.len(), threshold, |i| xs[i].to_f64(), |i| ys[i].to_f64())
+}
+
+/// Core of the largest triangle three buckets algorithm: given accessors for
+/// the `x` and `y` coordinate of each of the `data_len` points, returns the
+/// indices of the `threshold` points it selects.
+///
+/// This is the one place the bucket-selection loop (bucket averaging, then a
+/// triangle-area scan to pick the point that maximizes it) is implemented;
+/// both [`lttb_into`] (over `&[DataPoint]`) and [`lttb_indices`] (generic over
+/// [`LttbParam`]) are thin wrappers around it, parameterized by closures so
+/// neither has to materialize the other's representation.
+fn select_indices<FX, FY>(data_len: usize, threshold: usize, x: FX, y: FY) -> Vec<usize>
+where
+    FX: Fn(usize) -> f64,
+    FY: Fn(usize) -> f64,
+{
+    if threshold >= data_len || threshold == 0 {
         // Nothing to do.
-        return data;
+        return (0..data_len).collect();
+    }
+
+    if threshold == 1 {
+        // Can't pick an interior point; just keep the first one.
+        return vec![0];
+    }
+
+    if threshold == 2 {
+        return vec![0, data_len - 1];
     }
 
     let mut sampled = Vec::with_capacity(threshold);
 
     // Bucket size. Leave room for start and end data points.
-    let every = ((data.len() - 2) as f64) / ((threshold - 2) as f64);
+    let every = ((data_len - 2) as f64) / ((threshold - 2) as f64);
 
     // Initially a is the first point in the triangle.
     let mut a = 0;
 
     // Always add the first point.
-    sampled.push(data[a]);
+    sampled.push(a);
 
     for i in 0..threshold - 2 {
         // Calculate point average for next bucket (containing c).
@@ -92,17 +214,16 @@ pub fn lttb(data: Vec<DataPoint>, threshold: usize) -> Vec<DataPoint> {
         let avg_range_start = (((i + 1) as f64) * every) as usize + 1;
 
         let mut end = (((i + 2) as f64) * every) as usize + 1;
-        if end >= data.len() {
-            end = data.len();
+        if end >= data_len {
+            end = data_len;
         }
         let avg_range_end = end;
 
         let avg_range_length = (avg_range_end - avg_range_start) as f64;
 
-        for i in 0..(avg_range_end - avg_range_start) {
-            let idx = (avg_range_start + i) as usize;
-            avg_x += data[idx].x;
-            avg_y += data[idx].y;
+        for idx in avg_range_start..avg_range_end {
+            avg_x += x(idx);
+            avg_y += y(idx);
         }
         avg_x /= avg_range_length;
         avg_y /= avg_range_length;
@@ -112,52 +233,341 @@ pub fn lttb(data: Vec<DataPoint>, threshold: usize) -> Vec<DataPoint> {
         let range_to = (((i + 1) as f64) * every) as usize + 1;
 
         // Point a.
-        let point_a_x = data[a].x;
-        let point_a_y = data[a].y;
+        let point_a_x = x(a);
+        let point_a_y = y(a);
 
         let mut max_area = -1f64;
         let mut next_a = range_offs;
-        for i in 0..(range_to - range_offs) {
-            let idx = (range_offs + i) as usize;
-
+        for idx in range_offs..range_to {
             // Calculate triangle area over three buckets.
-            let area = ((point_a_x - avg_x) * (data[idx].y - point_a_y)
-                - (point_a_x - data[idx].x) * (avg_y - point_a_y))
-                .abs() * 0.5;
+            let area = ((point_a_x - avg_x) * (y(idx) - point_a_y)
+                - (point_a_x - x(idx)) * (avg_y - point_a_y))
+                .abs()
+                * 0.5;
             if area > max_area {
                 max_area = area;
                 next_a = idx; // Next a is this b.
             }
         }
 
-        sampled.push(data[next_a]); // Pick this point from the bucket.
+        sampled.push(next_a); // Pick this point from the bucket.
         a = next_a; // This a is the next a (chosen b).
     }
 
     // Always add the last point.
-    sampled.push(data[data.len() - 1]);
+    sampled.push(data_len - 1);
 
     sampled
 }
 
+/// Downsamples a structure-of-arrays time series `(xs, ys)` to `threshold`
+/// points using the largest triangle three buckets algorithm, and returns the
+/// chosen points as a new `Vec<DataPoint>`.
+///
+/// This mirrors [`lttb`] but accepts separate `x` and `y` slices of any
+/// numeric type instead of a `Vec<DataPoint>` of `f64`s, so columnar buffers
+/// can be downsampled without first being zipped together. It is implemented
+/// on top of [`lttb_indices`]; use that directly if you need the indices
+/// themselves, e.g. to downsample several aligned series consistently.
+///
+/// # Panics
+///
+/// Panics if `xs` and `ys` do not have the same length.
+pub fn lttb_sep<X, Y>(xs: &[X], ys: &[Y], threshold: usize) -> Vec<DataPoint>
+where
+    X: LttbParam,
+    Y: LttbParam,
+{
+    lttb_indices(xs, ys, threshold)
+        .into_iter()
+        .map(|i| DataPoint::new(xs[i].to_f64(), ys[i].to_f64()))
+        .collect()
+}
+
+/// Downsamples a structure-of-arrays time series `(xs, ys)` to `threshold`
+/// points using the MinMaxLTTB algorithm.
+///
+/// MinMaxLTTB first reduces the input to roughly `ratio * threshold`
+/// candidate points with a cheap MinMax pass, then runs the regular LTTB
+/// algorithm on just that candidate set. LTTB almost always ends up
+/// selecting local extrema anyway, so preselecting extrema this way
+/// preserves output quality while cutting the number of triangle area
+/// evaluations dramatically on very large inputs. A `ratio` around `30` is a
+/// good default.
+///
+/// Falls back to plain [`lttb_sep`] when `ratio * threshold >= xs.len()`.
+///
+/// # Panics
+///
+/// Panics if `xs` and `ys` do not have the same length.
+pub fn min_max_lttb<X, Y>(xs: &[X], ys: &[Y], threshold: usize, ratio: usize) -> Vec<DataPoint>
+where
+    X: LttbParam,
+    Y: LttbParam,
+{
+    assert_eq!(xs.len(), ys.len(), "xs and ys must have the same length");
+
+    let candidate_len = ratio * threshold;
+    if candidate_len >= xs.len() {
+        return lttb_sep(xs, ys, threshold);
+    }
+
+    let candidates = min_max_indices(ys, candidate_len);
+
+    let candidate_xs: Vec<f64> = candidates.iter().map(|&i| xs[i].to_f64()).collect();
+    let candidate_ys: Vec<f64> = candidates.iter().map(|&i| ys[i].to_f64()).collect();
+
+    lttb_indices(&candidate_xs, &candidate_ys, threshold)
+        .into_iter()
+        .map(|i| {
+            let orig = candidates[i];
+            DataPoint::new(xs[orig].to_f64(), ys[orig].to_f64())
+        })
+        .collect()
+}
+
+/// Reduces `ys` to a set of candidate indices for [`min_max_lttb`] by
+/// partitioning it into `candidate_len / 2` equal-sized ranges and, from each
+/// range with at least two points, keeping the indices of the minimum and
+/// maximum value (emitted in their original order). The first and last
+/// indices are always kept.
+fn min_max_indices<Y>(ys: &[Y], candidate_len: usize) -> Vec<usize>
+where
+    Y: LttbParam,
+{
+    let data_len = ys.len();
+    let num_ranges = candidate_len / 2;
+    if num_ranges == 0 || data_len == 0 {
+        return (0..data_len).collect();
+    }
+
+    // Convert once so the per-range scan below (potentially SIMD-accelerated,
+    // see `range_min_max`) runs over a contiguous `f64` slice regardless of
+    // what `Y` is.
+    let ys: Vec<f64> = ys.iter().map(|y| y.to_f64()).collect();
+
+    let mut indices = Vec::with_capacity(candidate_len + 2);
+    indices.push(0);
+
+    let range_len = data_len as f64 / num_ranges as f64;
+    for r in 0..num_ranges {
+        let range_start = ((r as f64) * range_len) as usize;
+        let range_end = ((((r + 1) as f64) * range_len) as usize).min(data_len);
+        if range_end - range_start < 2 {
+            // Skip ranges with fewer than two points.
+            continue;
+        }
+
+        let (min_rel, max_rel) = range_min_max(&ys[range_start..range_end]);
+        let min_idx = range_start + min_rel;
+        let max_idx = range_start + max_rel;
+
+        let (first, second) = if min_idx <= max_idx {
+            (min_idx, max_idx)
+        } else {
+            (max_idx, min_idx)
+        };
+        if first != 0 {
+            indices.push(first);
+        }
+        if second != first && second != 0 {
+            indices.push(second);
+        }
+    }
+
+    if *indices.last().unwrap() != data_len - 1 {
+        indices.push(data_len - 1);
+    }
+
+    indices
+}
+
+/// Finds the index of the minimum value and the index of the maximum value
+/// in `ys`.
+///
+/// With the `simd` feature enabled this delegates to the vectorized scan
+/// from the `argminmax` crate; without it, a plain scalar loop is used. Both
+/// produce the same result, so enabling the feature is purely a throughput
+/// win on the large inputs [`min_max_lttb`] is meant for.
+#[cfg(feature = "simd")]
+fn range_min_max(ys: &[f64]) -> (usize, usize) {
+    use argminmax::ArgMinMax;
+    ys.argminmax()
+}
+
+#[cfg(not(feature = "simd"))]
+fn range_min_max(ys: &[f64]) -> (usize, usize) {
+    let mut min_idx = 0;
+    let mut max_idx = 0;
+    for (idx, &y) in ys.iter().enumerate().skip(1) {
+        if y < ys[min_idx] {
+            min_idx = idx;
+        }
+        if y > ys[max_idx] {
+            max_idx = idx;
+        }
+    }
+    (min_idx, max_idx)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{lttb, DataPoint};
+    use super::{
+        lttb, lttb_indices, lttb_into, lttb_sep, min_max_indices, min_max_lttb, DataPoint,
+    };
 
     #[test]
     fn lttb_test() {
-        let mut dps = vec![];
-        dps.push(DataPoint::new(0.0, 10.0));
-        dps.push(DataPoint::new(1.0, 12.0));
-        dps.push(DataPoint::new(2.0, 8.0));
-        dps.push(DataPoint::new(3.0, 10.0));
-        dps.push(DataPoint::new(4.0, 12.0));
-
-        let mut expected = vec![];
-        expected.push(DataPoint::new(0.0, 10.0));
-        expected.push(DataPoint::new(2.0, 8.0));
-        expected.push(DataPoint::new(4.0, 12.0));
+        let dps = vec![
+            DataPoint::new(0.0, 10.0),
+            DataPoint::new(1.0, 12.0),
+            DataPoint::new(2.0, 8.0),
+            DataPoint::new(3.0, 10.0),
+            DataPoint::new(4.0, 12.0),
+        ];
+
+        let expected = vec![
+            DataPoint::new(0.0, 10.0),
+            DataPoint::new(2.0, 8.0),
+            DataPoint::new(4.0, 12.0),
+        ];
 
         assert_eq!(expected, lttb(dps, 3));
     }
+
+    #[test]
+    fn lttb_into_test() {
+        let dps = vec![
+            DataPoint::new(0.0, 10.0),
+            DataPoint::new(1.0, 12.0),
+            DataPoint::new(2.0, 8.0),
+            DataPoint::new(3.0, 10.0),
+            DataPoint::new(4.0, 12.0),
+        ];
+
+        let expected = vec![
+            DataPoint::new(0.0, 10.0),
+            DataPoint::new(2.0, 8.0),
+            DataPoint::new(4.0, 12.0),
+        ];
+
+        // Fill `out` with unrelated data first to make sure `lttb_into`
+        // clears it before writing, but keeps the underlying capacity.
+        let mut out = Vec::with_capacity(16);
+        out.push(DataPoint::new(99.0, 99.0));
+        let capacity_before = out.capacity();
+
+        lttb_into(&dps, 3, &mut out);
+
+        assert_eq!(expected, out);
+        assert_eq!(capacity_before, out.capacity());
+    }
+
+    #[test]
+    fn lttb_sep_test() {
+        let xs: Vec<i64> = vec![0, 1, 2, 3, 4];
+        let ys: Vec<f32> = vec![10.0, 12.0, 8.0, 10.0, 12.0];
+
+        let expected = vec![
+            DataPoint::new(0.0, 10.0),
+            DataPoint::new(2.0, 8.0),
+            DataPoint::new(4.0, 12.0),
+        ];
+
+        assert_eq!(expected, lttb_sep(&xs, &ys, 3));
+    }
+
+    #[test]
+    fn lttb_indices_test() {
+        let xs: Vec<i64> = vec![0, 1, 2, 3, 4];
+        let ys: Vec<f32> = vec![10.0, 12.0, 8.0, 10.0, 12.0];
+
+        assert_eq!(vec![0, 2, 4], lttb_indices(&xs, &ys, 3));
+    }
+
+    #[test]
+    fn min_max_lttb_test() {
+        let xs: Vec<i64> = vec![0, 1, 2, 3, 4];
+        let ys: Vec<f32> = vec![10.0, 12.0, 8.0, 10.0, 12.0];
+
+        let expected = vec![
+            DataPoint::new(0.0, 10.0),
+            DataPoint::new(2.0, 8.0),
+            DataPoint::new(4.0, 12.0),
+        ];
+
+        // ratio * threshold (30 * 3) is well above the data length, so this
+        // falls back to plain LTTB.
+        assert_eq!(expected, min_max_lttb(&xs, &ys, 3, 30));
+    }
+
+    #[test]
+    fn min_max_lttb_preselects_candidates_test() {
+        let xs: Vec<f64> = (0..20).map(|i| i as f64).collect();
+        let ys: Vec<f64> = xs
+            .iter()
+            .map(|&x| (x * 0.9).sin() + x * 0.01)
+            .collect();
+
+        // ratio * threshold (2 * 4 = 8) is smaller than the data length (20),
+        // so the MinMax preselection pass runs before LTTB.
+        let sampled = min_max_lttb(&xs, &ys, 4, 2);
+
+        assert_eq!(4, sampled.len());
+        assert_eq!(DataPoint::new(xs[0], ys[0]), sampled[0]);
+        assert_eq!(DataPoint::new(xs[19], ys[19]), sampled[3]);
+    }
+
+    #[test]
+    fn min_max_lttb_skips_short_ranges_test() {
+        // 7 points split into candidate_len / 2 = 3 ranges of ~2.33 points
+        // each: integer rounding gives them 2, 2 and 3 points, so the ranges
+        // are uneven but none is short.
+        let xs: Vec<f64> = (0..7).map(|i| i as f64).collect();
+        let ys: Vec<f64> = vec![0.0, 5.0, 1.0, 4.0, 2.0, 3.0, 9.0];
+
+        // ratio * threshold (2 * 3 = 6) is smaller than the data length (7),
+        // so the MinMax preselection pass runs.
+        let sampled = min_max_lttb(&xs, &ys, 3, 2);
+
+        assert_eq!(3, sampled.len());
+        assert_eq!(DataPoint::new(xs[0], ys[0]), sampled[0]);
+        assert_eq!(DataPoint::new(xs[6], ys[6]), sampled[2]);
+
+        // Since `min_max_lttb` only preselects when candidate_len is below
+        // the data length, its ranges always hold at least two points, so
+        // the short-range skip is exercised on `min_max_indices` directly:
+        // 5 points split into 8 / 2 = 4 ranges of 1.25 points give ranges of
+        // 1, 1, 1 and 2 points. The three single-point ranges must be skipped
+        // instead of contributing a bogus min/max pair; only the last range
+        // [3, 5) contributes its max (index 3) and min (index 4).
+        assert_eq!(vec![0, 3, 4], min_max_indices(&ys[..5], 8));
+    }
+
+    #[test]
+    #[should_panic(expected = "xs and ys must have the same length")]
+    fn lttb_indices_mismatched_lengths_test() {
+        let xs: Vec<f64> = vec![0.0, 1.0, 2.0];
+        let ys: Vec<f64> = vec![0.0, 1.0];
+
+        lttb_indices(&xs, &ys, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "xs and ys must have the same length")]
+    fn lttb_sep_mismatched_lengths_test() {
+        let xs: Vec<f64> = vec![0.0, 1.0, 2.0];
+        let ys: Vec<f64> = vec![0.0, 1.0];
+
+        lttb_sep(&xs, &ys, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "xs and ys must have the same length")]
+    fn min_max_lttb_mismatched_lengths_test() {
+        let xs: Vec<f64> = vec![0.0, 1.0, 2.0];
+        let ys: Vec<f64> = vec![0.0, 1.0];
+
+        min_max_lttb(&xs, &ys, 2, 30);
+    }
 }